@@ -2,12 +2,19 @@ from __future__ import annotations
 
 from dataclasses import dataclass, field
 
-from moves import Position, get_valid_moves_rook, get_valid_moves_pawn, get_valid_moves_bishop, get_valid_moves_knight, get_valid_moves_queen, get_possible_moves_king
+from moves import Position, on_board, get_valid_moves_rook, get_valid_moves_pawn, get_valid_moves_bishop, get_valid_moves_knight, get_valid_moves_queen, get_possible_moves_king
 from pieces import Colour, Piece, PieceType
 
 Grid = {} 
 #dict[Position, Piece]
 
+FILES = "abcdefgh"
+
+
+def to_notation(position: Position) -> str:
+    x, y = position
+    return f"{FILES[x]}{y + 1}"
+
 
 def empty_board() -> Grid:
     grid: Grid = {}
@@ -22,7 +29,7 @@ class Board:
     pieces: Grid = field(default_factory=empty_board)
 
     @staticmethod #https://www.chess.com/terms/fen-chess fen strings start from top left (0,7)
-    def from_fen(fen: str) -> Board:
+    def from_fen(fen: str, strict: bool = False) -> Board:
         board = Board()
         fenlist = fen.split("/")
 
@@ -38,6 +45,9 @@ class Board:
                 else:
                     #from_fen places an actual Piece
                     board.place(Piece.from_fen(indx + extra, 7 - indy, x))
+        #strict mode refuses positions such as pawns on the first or eighth rank
+        if strict and not board.is_plausible():
+            raise ValueError(f"Implausible position in FEN: {fen}")
         return board
 
     def place(self, piece: Piece) -> None:
@@ -49,16 +59,88 @@ class Board:
     def piece_type(self, x:int, y:int) -> PieceType:
         return self.piece(x, y).type
 
+    #off-board squares are neither empty nor occupied, so generators stop at the edge
     def empty(self, x: int, y: int) -> bool:
-        return self.piece(x, y).type == PieceType.EMPTY
-
-    def find_king(self, colour: Colour) -> Piece:
+        return on_board(x, y) and self.piece(x, y).type == PieceType.EMPTY
+
+    def has_ally(self, x: int, y: int, colour: Colour) -> bool:
+        return on_board(x, y) and self.piece(x, y).colour == colour
+
+    def has_enemy(self, x: int, y: int, colour: Colour) -> bool:
+        return on_board(x, y) and self.piece(x, y).colour not in (colour, Colour.NONE)
+
+    def find_pieces(self, colour: Colour, piece_type: PieceType) -> list[Position]:
+        return [
+            position for position, piece in self.pieces.items()
+            if piece.type == piece_type and piece.colour == colour
+        ]
+
+    def find_king(self, colour: Colour) -> Piece | None:
+        kings = self.find_pieces(colour, PieceType.KING)
+        if kings:
+            return self.piece(*kings[0])
+        return None
+
+    def same_position(self, other: Board) -> bool:
+        #like ==, but ignores has_moved/moves_made/last_moved on the pieces
+        return all(
+            (piece.colour, piece.type) == (other.pieces[pos].colour, other.pieces[pos].type)
+            for pos, piece in self.pieces.items()
+        )
+
+    def piece_counts(self) -> dict[tuple[Colour, PieceType], int]:
+        counts: dict[tuple[Colour, PieceType], int] = {}
         for piece in self.pieces.values():
-            if piece.type == PieceType.KING and colour == piece.colour:
-                return piece
+            if piece.type != PieceType.EMPTY:
+                key = (piece.colour, piece.type)
+                counts[key] = counts.get(key, 0) + 1
+        return counts
+
+    def is_plausible(self) -> bool:
+        #cheap sanity check, not full legality: piece counts and pawns off the back ranks
+        counts = self.piece_counts()
+        for colour in (Colour.WHITE, Colour.BLACK):
+            if counts.get((colour, PieceType.PAWN), 0) > 8:
+                return False
+            if counts.get((colour, PieceType.KING), 0) > 1:
+                return False
+        return not any(
+            piece.type == PieceType.PAWN and piece.y in (0, 7)
+            for piece in self.pieces.values()
+        )
+
+    def material(self, colour: Colour) -> int:
+        return sum(piece.value for piece in self.pieces.values() if piece.colour == colour)
+
+    def material_balance(self) -> int:
+        #positive when white is ahead
+        return self.material(Colour.WHITE) - self.material(Colour.BLACK)
 
     def get_valid_moves(self, x: int, y: int) -> list[Position]:
-        return MOVE_LISTS[self.piece(x, y)]
+        if self.empty(x, y):
+            return []
+        return MOVE_LISTS[self.piece_type(x, y)](self, x, y)
+
+    def describe(self) -> str:
+        #every occupied square from a1 to h8, e.g. "White rook a1, White knight b1, ..."
+        return ", ".join(
+            f"{piece.colour.name.capitalize()} {piece.type.value} {to_notation(position)}"
+            for position, piece in sorted(self.pieces.items(), key=lambda item: (item[0][1], item[0][0]))
+            if piece.type != PieceType.EMPTY
+        )
+
+    def to_unicode_diagram(self) -> str:
+        #white at the bottom, boxed and labelled for pasting into chat or a forum
+        rows = ["  ┌" + "─" * 17 + "┐"]
+        for y in range(7, -1, -1):
+            squares = " ".join(self.piece(x, y).diagram for x in range(8))
+            rows.append(f"{y + 1} │ {squares} │")
+        rows.append("  └" + "─" * 17 + "┘")
+        rows.append("    " + " ".join(FILES))
+        return "\n".join(rows)
+
+    def valid_destinations_algebraic(self, x: int, y: int) -> list[str]:
+        return sorted(to_notation(move) for move in self.get_valid_moves(x, y))
 
 
 MOVE_LISTS = {
@@ -71,15 +153,23 @@ MOVE_LISTS = {
 }
 
 
-def draw_board_white(board: Board) -> None:
+#rank numbers go on both sides, file letters once along the bottom
+def draw_board_white(board: Board, ascii_only: bool = False) -> None:
     for y in range(7,-1,-1):
+        print(y + 1, end=' ')
         for x in range(8):
-            print(board.piece(x,y), end=' ')
-        print("\t")
+            piece = board.piece(x,y)
+            print(piece.ascii if ascii_only else piece, end=' ')
+        print(y + 1)
+    print("  " + " ".join(FILES))
 
 
-def draw_board_black(board: Board) -> None:
+#black's view is the board turned around, so the files run h to a as well
+def draw_board_black(board: Board, ascii_only: bool = False) -> None:
     for y in range(8):
-        for x in range(8):
-            print(board.piece(x,y), end=' ')
-        print("\t")
\ No newline at end of file
+        print(y + 1, end=' ')
+        for x in range(7,-1,-1):
+            piece = board.piece(x,y)
+            print(piece.ascii if ascii_only else piece, end=' ')
+        print(y + 1)
+    print("  " + " ".join(reversed(FILES)))
\ No newline at end of file
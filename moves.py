@@ -1,6 +1,6 @@
 from typing import Protocol
 
-from pieces import Piece
+from pieces import Colour, Piece
 
 Position = tuple([int, int])
 
@@ -11,47 +11,42 @@ class Board(Protocol):
     def piece(self, x: int, y: int) -> Piece:
         """Returns the piece at position (x, y)."""
 
+    def has_ally(self, x: int, y: int, colour: Colour) -> bool:
+        """Whether (x, y) is on the board and holds a piece of the given colour."""
+
+    def has_enemy(self, x: int, y: int, colour: Colour) -> bool:
+        """Whether (x, y) is on the board and holds a piece of the other colour."""
+
+
+def on_board(x: int, y: int) -> bool:
+    return 0 <= x < 8 and 0 <= y < 8
+
 
 def get_valid_moves_pawn(square: Board, x: int, y: int) -> list:
     valid_moves: list = []
+    colour = square.piece(x, y).colour
     #Moves for white pawns
-    if square.piece(x, y).colour.value == 0:
+    if colour.value == 0:
         #Pawns can move 2 squares on 1st move
-        if y == 1 and square.empty(x, y + 2):
+        if y == 1 and square.empty(x, y + 1) and square.empty(x, y + 2):
             valid_moves.append((x, y + 2))
-        #checking top left and right captures
-        if 0 < x > 7:
-            for i in range(-1,1,2):
-                if square.piece(x + i, y + 1).colour.value == 1:
-                    valid_moves.append((x + i, y + 1))
-        #edge pawn so doesn't check outside of the board
-        elif x == 0:
-            if square.piece(x + 1, y + 1).colour.value == 1:
-                    valid_moves.append((x + 1, y + 1))
-        elif x == 7:
-            if square.piece(x - 1, y + 1).colour.value == 1:
-                    valid_moves.append((x - 1, y + 1))
+        #checking top left and right captures, has_enemy is false off the board
+        for i in (-1, 1):
+            if square.has_enemy(x + i, y + 1, colour):
+                valid_moves.append((x + i, y + 1))
         #check the square infont of current pawn
         if square.empty(x, y + 1):
             valid_moves.append((x, y + 1))
         return valid_moves
 
     #Moves for black pawns
-    if square.piece(x, y).colour.value== 1:
-        if y == 6 and square.empty(x, y - 2):
+    if colour.value == 1:
+        if y == 6 and square.empty(x, y - 1) and square.empty(x, y - 2):
             valid_moves.append((x, y - 2))
-        #checking top left and right captures
-        if 0 < x > 7:
-            for i in range(-1,1,2):
-                if square.piece(x + i, y - 1).colour.value == 0:
-                    valid_moves.append((x + i, y - 1))
-        #edge pawn so doesn't check outside of the board
-        elif x == 0:
-            if square.piece(x + 1, y - 1).colour.value == 0:
-                    valid_moves.append((x + 1, y - 1))
-        elif x == 7:
-            if square.piece(x - 1, y - 1).colour.value == 0:
-                    valid_moves.append((x - 1, y - 1))
+        #checking bottom left and right captures, has_enemy is false off the board
+        for i in (-1, 1):
+            if square.has_enemy(x + i, y - 1, colour):
+                valid_moves.append((x + i, y - 1))
         #check the square infont of current pawn
         if square.empty(x, y - 1):
             valid_moves.append((x, y - 1))
@@ -60,6 +55,7 @@ def get_valid_moves_pawn(square: Board, x: int, y: int) -> list:
     
 def get_valid_moves_bishop(square: Board, x: int, y: int) -> list:
     valid_moves: list = []
+    colour = square.piece(x, y).colour
     init_x = x
     init_y = y
 
@@ -69,43 +65,46 @@ def get_valid_moves_bishop(square: Board, x: int, y: int) -> list:
         y += 1
         if square.empty(x, y):
             valid_moves.append((x, y))
-        elif square.piece(x, y).colour.value != square.piece(init_x, init_y).colour.value:
+        elif square.has_enemy(x, y, colour):
             valid_moves.append((x, y))
             break
         else:
             break
     
     #Check squares top right of the piece
+    x, y = init_x, init_y
     while x < 8 or y < 8:
         x += 1 
         y += 1
         if square.empty(x,y):
             valid_moves.append((x, y))
-        elif square.piece(x, y).colour.value != square.piece(init_x, init_y).colour.value:
+        elif square.has_enemy(x, y, colour):
             valid_moves.append((x, y))
             break
         else:
             break
 
     #Check squares bottom left of the piece
+    x, y = init_x, init_y
     while x > -1 or y > -1:
         x -= 1
         y -= 1
         if square.empty(x, y):
             valid_moves.append((x, y))
-        elif square.piece(x, y).colour.value != square.piece(init_x, init_y).colour.value:
+        elif square.has_enemy(x, y, colour):
             valid_moves.append((x, y))
             break
         else:
             break
 
     #Check squares bottom right of the piece
+    x, y = init_x, init_y
     while x < 8 or y > -1:
         x += 1
         y -= 1
         if square.empty(x, y):
             valid_moves.append((x, y))
-        elif square.piece(x, y).colour.value != square.piece(init_x, init_y).colour.value:
+        elif square.has_enemy(x, y, colour):
             valid_moves.append((x, y))
             break
         else:
@@ -121,33 +120,28 @@ def get_valid_moves_knight(square: Board, x: int, y: int) -> list:
         (x+1, y+2),
         (x+2, y+1),
         (x+2, y-1),
-        (x+2, y-2),
+        (x+1, y-2),
         (x-1, y-2),
         (x-2, y-1),
     ]
     
+    colour = square.piece(x, y).colour
     for move in possible_moves:
         move_x, move_y = move
-        #so it doesn't check out of bound squares
-        print(move_x, move_y)
-        if 8 > move_x > -1 and 8 > move_y > -1:
-            print(square.empty(move_x, move_y))
-            if square.empty(move_x, move_y) == True:
-                print(move)
-                valid_moves.append(move)
-            elif square.piece(move_x, move_y).colour.value != square.piece(x, y).colour.value:
-                valid_moves.append(move)
-            else: pass
+        #empty and has_enemy are both false for out of bound squares
+        if square.empty(move_x, move_y) or square.has_enemy(move_x, move_y, colour):
+            valid_moves.append(move)
     return valid_moves
 
 
 def get_valid_moves_rook(square: Board, x: int, y: int) -> list:
     valid_moves: list = []
+    colour = square.piece(x, y).colour
     #Check squares to the right of the piece
     for i in range(1, 8 - x):
         if square.empty(x + i, y):
             valid_moves.append((x + i, y))
-        elif square.piece(x + i, y).colour.value != square.piece(x, y).colour.value:
+        elif square.has_enemy(x + i, y, colour):
             valid_moves.append((x + i, y))
             break
         else:
@@ -157,7 +151,7 @@ def get_valid_moves_rook(square: Board, x: int, y: int) -> list:
     for i in range(1, x + 1):
         if square.empty(x - i, y):
             valid_moves.append((x - i, y))
-        elif square.piece(x, y).colour.value != square.piece(y, x - i).colour.value:
+        elif square.has_enemy(x - i, y, colour):
             valid_moves.append((x - i, y))
             break
         else:
@@ -167,7 +161,7 @@ def get_valid_moves_rook(square: Board, x: int, y: int) -> list:
     for i in range(1, 8 - y):
         if square.empty(x, y + i):
             valid_moves.append((x, y + i))
-        elif square.piece(x, y).colour.value != square.piece(x, y + i).colour.value:
+        elif square.has_enemy(x, y + i, colour):
             valid_moves.append((x, y + i))
             break
         else:
@@ -177,7 +171,7 @@ def get_valid_moves_rook(square: Board, x: int, y: int) -> list:
     for i in range(1, y + 1):
         if square.empty(x, y - i):
             valid_moves.append((x, y - i))
-        elif square.piece(x, y).colour.value != square.piece(x, y - i).colour.value:
+        elif square.has_enemy(x, y - i, colour):
             valid_moves.append((x, y - i))
             break
         else:
@@ -186,9 +180,7 @@ def get_valid_moves_rook(square: Board, x: int, y: int) -> list:
 
 
 def get_valid_moves_queen(square: Board, x: int, y: int) -> list:
-    valid_moves: list = [
-    get_valid_moves_bishop(square, x, y) + get_valid_moves_rook(square, x, y)
-    ]
+    valid_moves: list = get_valid_moves_bishop(square, x, y) + get_valid_moves_rook(square, x, y)
     return valid_moves
 
 
@@ -206,12 +198,10 @@ def get_possible_moves_king(square: Board, x: int, y: int) -> list:
         (x-1,y+1),
     ]
     
+    colour = square.piece(x, y).colour
     for move in moves:
         move_x, move_y = move
-
-        if square.empty(move_x, move_y):
-            possible_moves.append(move)
-        elif square.piece(move_x, move_y).colour.value != square.piece(x, y).colour.value:
+        #empty and has_enemy are both false for out of bound squares
+        if square.empty(move_x, move_y) or square.has_enemy(move_x, move_y, colour):
             possible_moves.append(move)
-        else: pass
     return possible_moves
\ No newline at end of file
@@ -41,6 +41,20 @@ FEN_MAP: dict[str, PieceType] = {
 }
 
 
+FEN_STR: dict[PieceType, str] = {piece_type: fen for fen, piece_type in FEN_MAP.items()}
+
+
+PIECE_VALUE: dict[PieceType, int] = {
+    PieceType.EMPTY: 0,
+    PieceType.PAWN: 1,
+    PieceType.ROOK: 5,
+    PieceType.BISHOP: 3,
+    PieceType.QUEEN: 9,
+    PieceType.KING: 0,
+    PieceType.KNIGHT: 3,
+}
+
+
 @dataclass
 class Piece:
     x: int
@@ -64,9 +78,28 @@ class Piece:
     def promote_to_queen(self) -> None:
         self.type = PieceType.QUEEN
 
+    @property
+    def value(self) -> int:
+        return PIECE_VALUE[self.type]
+
     @property
     def image(self) -> str:
         return f"pieces/{self.type}{self.colour.value.value}.png"
 
+    @property
+    def diagram(self) -> str:
+        #PIECE_STR gives white the filled glyphs for dark terminals, swap them for standard unicode colours
+        if self.type == PieceType.EMPTY:
+            return str(self)
+        return PIECE_STR[self.type][1 - self.colour.value]
+
+    @property
+    def ascii(self) -> str:
+        #plain letters for terminals without the unicode glyphs, "#" marks dark squares
+        if self.type == PieceType.EMPTY:
+            return "#" if (self.x + self.y) % 2 == 0 else "."
+        fen = FEN_STR[self.type]
+        return fen.upper() if self.colour == Colour.WHITE else fen
+
     def __str__(self):
         return PIECE_STR[self.type][self.colour.value]
\ No newline at end of file
@@ -0,0 +1,170 @@
+import io
+import unittest
+from contextlib import redirect_stdout
+
+from board import Board, draw_board_black, draw_board_white
+from pieces import Colour, PieceType
+
+START = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR"
+
+
+class TestValidMoves(unittest.TestCase):
+    def test_knight_moves(self):
+        board = Board.from_fen(START)
+        self.assertEqual(set(board.get_valid_moves(1, 0)), {(0, 2), (2, 2)})
+        board = Board.from_fen("8/8/8/8/4N3/8/8/8")
+        self.assertEqual(
+            set(board.get_valid_moves(4, 3)),
+            {(2, 2), (2, 4), (3, 1), (3, 5), (5, 1), (5, 5), (6, 2), (6, 4)},
+        )
+
+    def test_queen_returns_flat_unique_positions(self):
+        board = Board.from_fen("8/8/8/8/3Q4/8/8/8")
+        moves = board.get_valid_moves(3, 3)
+        self.assertEqual(len(moves), 27)
+        self.assertEqual(len(set(moves)), len(moves))
+        for square in [(7, 7), (0, 0), (7, 3), (3, 0)]:
+            self.assertIn(square, moves)
+
+    def test_empty_square_has_no_moves(self):
+        board = Board.from_fen(START)
+        self.assertEqual(board.get_valid_moves(4, 4), [])
+
+
+class TestDestinationsAlgebraic(unittest.TestCase):
+    def test_starting_knight(self):
+        board = Board.from_fen(START)
+        self.assertEqual(board.valid_destinations_algebraic(1, 0), ["a3", "c3"])
+
+    def test_queen_lists_each_square_once(self):
+        board = Board.from_fen("8/8/8/8/3Q4/8/8/8")
+        destinations = board.valid_destinations_algebraic(3, 3)
+        self.assertEqual(len(destinations), 27)
+        self.assertEqual(len(set(destinations)), 27)
+        self.assertIn("h8", destinations)
+
+
+class TestDescribe(unittest.TestCase):
+    def test_starting_position_lists_all_pieces(self):
+        entries = Board.from_fen(START).describe().split(", ")
+        self.assertEqual(len(entries), 32)
+        self.assertEqual(entries[:5], ["White rook a1", "White knight b1", "White bishop c1", "White queen d1", "White king e1"])
+        self.assertEqual(entries[8], "White pawn a2")
+        self.assertEqual(entries[16], "Black pawn a7")
+        self.assertEqual(entries[-1], "Black rook h8")
+
+
+class TestUnicodeDiagram(unittest.TestCase):
+    def test_starting_position_glyph_rows(self):
+        rows = Board.from_fen(START).to_unicode_diagram().split("\n")
+        self.assertEqual(rows[1], "8 │ ♜ ♞ ♝ ♛ ♚ ♝ ♞ ♜ │")
+        self.assertEqual(rows[2], "7 │ ♟ ♟ ♟ ♟ ♟ ♟ ♟ ♟ │")
+        self.assertEqual(rows[3], "6 │ • • • • • • • • │")
+        self.assertEqual(rows[7], "2 │ ♙ ♙ ♙ ♙ ♙ ♙ ♙ ♙ │")
+        self.assertEqual(rows[8], "1 │ ♖ ♘ ♗ ♕ ♔ ♗ ♘ ♖ │")
+        self.assertEqual(rows[-1], "    a b c d e f g h")
+
+
+class TestMaterial(unittest.TestCase):
+    def test_starting_position_is_balanced(self):
+        board = Board.from_fen(START)
+        self.assertEqual(board.material(Colour.WHITE), 39)
+        self.assertEqual(board.material_balance(), 0)
+
+    def test_up_a_rook(self):
+        board = Board.from_fen("1nbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR")
+        self.assertEqual(board.material_balance(), 5)
+
+
+class TestPlausibility(unittest.TestCase):
+    def test_starting_position_counts(self):
+        counts = Board.from_fen(START).piece_counts()
+        self.assertEqual(counts[(Colour.WHITE, PieceType.PAWN)], 8)
+        self.assertEqual(counts[(Colour.BLACK, PieceType.KNIGHT)], 2)
+        self.assertEqual(sum(counts.values()), 32)
+
+    def test_starting_position_is_plausible(self):
+        self.assertTrue(Board.from_fen(START).is_plausible())
+
+    def test_implausible_positions(self):
+        self.assertFalse(Board.from_fen("k6k/8/8/8/8/8/8/7K").is_plausible())
+        self.assertFalse(Board.from_fen("k7/pppppppp/p7/8/8/8/8/7K").is_plausible())
+        self.assertFalse(Board.from_fen("k7/8/8/8/8/8/8/P6K").is_plausible())
+
+
+class TestStrictFen(unittest.TestCase):
+    def test_pawn_on_a1_rejected_in_strict_mode(self):
+        with self.assertRaises(ValueError):
+            Board.from_fen("k7/8/8/8/8/8/8/P6K", strict=True)
+
+    def test_pawn_on_a1_allowed_by_default(self):
+        board = Board.from_fen("k7/8/8/8/8/8/8/P6K")
+        self.assertEqual(board.piece_type(0, 0), PieceType.PAWN)
+
+    def test_starting_position_loads_in_strict_mode(self):
+        self.assertTrue(Board.from_fen(START, strict=True).is_plausible())
+
+
+class TestFindPieces(unittest.TestCase):
+    def test_finds_both_white_knights(self):
+        board = Board.from_fen(START)
+        self.assertEqual(sorted(board.find_pieces(Colour.WHITE, PieceType.KNIGHT)), [(1, 0), (6, 0)])
+
+    def test_find_king(self):
+        board = Board.from_fen(START)
+        king = board.find_king(Colour.BLACK)
+        self.assertEqual((king.x, king.y), (4, 7))
+        self.assertIsNone(Board.from_fen("8/8/8/8/8/8/8/8").find_king(Colour.WHITE))
+
+
+class TestDrawBoard(unittest.TestCase):
+    def draw(self, draw_board) -> list[str]:
+        out = io.StringIO()
+        with redirect_stdout(out):
+            draw_board(Board.from_fen(START), ascii_only=True)
+        return out.getvalue().splitlines()
+
+    def test_black_view_starts_at_rank_one_from_h_file(self):
+        rows = self.draw(draw_board_black)
+        self.assertEqual(rows[0], "1 R N B K Q B N R 1")
+        self.assertEqual(rows[7], "8 r n b k q b n r 8")
+        self.assertEqual(rows[8], "  h g f e d c b a")
+
+    def test_white_view_starts_at_rank_eight_from_a_file(self):
+        rows = self.draw(draw_board_white)
+        self.assertEqual(rows[0], "8 r n b q k b n r 8")
+        self.assertEqual(rows[8], "  a b c d e f g h")
+
+
+class TestSamePosition(unittest.TestCase):
+    def test_ignores_move_counters(self):
+        a = Board.from_fen(START)
+        b = Board.from_fen(START)
+        b.piece(1, 0).has_moved = True
+        b.piece(1, 0).moves_made = 2
+        self.assertFalse(a == b)
+        self.assertTrue(a.same_position(b))
+
+    def test_different_pieces_are_not_the_same_position(self):
+        a = Board.from_fen(START)
+        self.assertFalse(a.same_position(Board.from_fen("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBRR")))
+        self.assertFalse(a.same_position(Board.from_fen("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNr")))
+
+
+class TestAsciiBoard(unittest.TestCase):
+    def test_ascii_mode_prints_only_ascii(self):
+        out = io.StringIO()
+        with redirect_stdout(out):
+            draw_board_white(Board.from_fen(START), ascii_only=True)
+        self.assertTrue(out.getvalue().isascii())
+
+    def test_empty_square_colours(self):
+        board = Board.from_fen(START)
+        self.assertEqual(board.piece(0, 2).ascii, "#")
+        self.assertEqual(board.piece(0, 5).ascii, ".")
+        self.assertEqual(board.piece(0, 1).ascii, "P")
+        self.assertEqual(board.piece(0, 6).ascii, "p")
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,99 @@
+import io
+import unittest
+from contextlib import redirect_stdout
+
+from board import Board
+from moves import get_valid_moves_bishop, get_valid_moves_knight, on_board
+from pieces import Colour
+
+START = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR"
+
+
+class TestBoundsSafety(unittest.TestCase):
+    def test_on_board(self):
+        self.assertTrue(on_board(0, 0))
+        self.assertTrue(on_board(7, 7))
+        self.assertFalse(on_board(-1, 0))
+        self.assertFalse(on_board(0, 8))
+
+    def test_off_board_squares_are_neither_empty_nor_occupied(self):
+        board = Board.from_fen(START)
+        for x, y in [(-1, 0), (8, 0), (0, -1), (3, 8)]:
+            self.assertFalse(board.empty(x, y))
+            self.assertFalse(board.has_ally(x, y, Colour.WHITE))
+            self.assertFalse(board.has_enemy(x, y, Colour.WHITE))
+
+    def test_ally_and_enemy(self):
+        board = Board.from_fen(START)
+        self.assertTrue(board.has_ally(0, 0, Colour.WHITE))
+        self.assertTrue(board.has_enemy(0, 7, Colour.WHITE))
+        self.assertFalse(board.has_enemy(0, 3, Colour.WHITE))
+
+
+class TestBishop(unittest.TestCase):
+    def test_lone_bishop_covers_both_diagonals(self):
+        board = Board.from_fen("8/8/8/8/3B4/8/8/8")
+        moves = get_valid_moves_bishop(board, 3, 3)
+        self.assertEqual(len(moves), 13)
+        self.assertEqual(len(set(moves)), 13)
+        for square in [(0, 6), (7, 7), (0, 0), (6, 0)]:
+            self.assertIn(square, moves)
+
+    def test_bishop_blocked_by_own_pawns(self):
+        board = Board.from_fen(START)
+        self.assertEqual(board.get_valid_moves(2, 0), [])
+
+    def test_bishop_stops_on_capture(self):
+        board = Board.from_fen("8/8/5p2/8/3B4/8/8/8")
+        moves = get_valid_moves_bishop(board, 3, 3)
+        self.assertIn((5, 5), moves)
+        self.assertNotIn((6, 6), moves)
+
+
+class TestPawn(unittest.TestCase):
+    def test_pawn_captures_off_the_edge_files(self):
+        board = Board.from_fen("8/8/8/8/8/p1p5/1P6/8")
+        self.assertEqual(sorted(board.get_valid_moves(1, 1)), [(0, 2), (1, 2), (1, 3), (2, 2)])
+
+    def test_edge_pawn_does_not_look_off_board(self):
+        board = Board.from_fen("8/8/8/8/8/1p6/P7/8")
+        self.assertEqual(sorted(board.get_valid_moves(0, 1)), [(0, 2), (0, 3), (1, 2)])
+
+    def test_double_push_blocked_by_piece_in_between(self):
+        board = Board.from_fen("8/8/8/8/8/n7/P7/8")
+        self.assertEqual(board.get_valid_moves(0, 1), [])
+        board = Board.from_fen("8/p7/N7/8/8/8/8/8")
+        self.assertEqual(board.get_valid_moves(0, 6), [])
+
+    def test_double_push_from_start(self):
+        board = Board.from_fen(START)
+        self.assertEqual(sorted(board.get_valid_moves(4, 1)), [(4, 2), (4, 3)])
+        self.assertEqual(sorted(board.get_valid_moves(4, 6)), [(4, 4), (4, 5)])
+
+
+class TestRook(unittest.TestCase):
+    def test_rook_captures_to_the_left(self):
+        board = Board.from_fen("8/8/8/8/8/8/p2R4/8")
+        moves = board.get_valid_moves(3, 1)
+        self.assertIn((0, 1), moves)
+        self.assertEqual(len(moves), 14)
+
+
+class TestKnight(unittest.TestCase):
+    def test_central_knight_squares(self):
+        board = Board.from_fen("8/8/8/8/3N4/8/8/8")
+        self.assertEqual(
+            board.valid_destinations_algebraic(3, 3),
+            ["b3", "b5", "c2", "c6", "e2", "e6", "f3", "f5"],
+        )
+
+    def test_knight_prints_nothing(self):
+        board = Board.from_fen(START)
+        out = io.StringIO()
+        with redirect_stdout(out):
+            get_valid_moves_knight(board, 1, 0)
+        self.assertEqual(out.getvalue(), "")
+
+
+if __name__ == "__main__":
+    unittest.main()